@@ -1,6 +1,13 @@
+// This is a demonstration file accompanying a blog post: several units,
+// macros and the `sqrt` impl at the bottom are kept around for the reader
+// even though `main()` doesn't exercise every single one of them.
+#![allow(dead_code, unused_macros)]
+
 use std::marker::PhantomData;
 use std::fmt;
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Sub, Mul, Div, Neg, Rem, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::iter::Sum;
+use std::str::FromStr;
 use std::mem;
 use std::cmp::Ordering;
 
@@ -36,6 +43,11 @@ macro_rules! NewLength {
 NewLength!(Meters, "meter", 1_000_000_000);
 NewLength!(Millimeters, "millimeter", 1_000_000);
 NewLength!(Kilometers, "kilometer", 1_000_000_000_000);
+NewLength!(Centimeters, "centimeter", 10_000_000);
+NewLength!(Micrometers, "micrometer", 1_000);
+NewLength!(Inches, "inch", 25_400_000);
+NewLength!(Feet, "foot", 304_800_000);
+NewLength!(Miles, "mile", 1_609_340_000_000);
 
 
 impl<T> fmt::Display for Length<T> where T: LengthUnit {
@@ -55,6 +67,160 @@ impl<T> fmt::Display for Length<T> where T: LengthUnit {
 }
 
 
+// error returned by Length's FromStr impl
+#[derive(Debug, Clone, PartialEq)]
+enum ParseLengthError {
+    MalformedNumber(String),
+    UnitMismatch { expected: String, found: String },
+}
+
+impl fmt::Display for ParseLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseLengthError::MalformedNumber(s) =>
+                write!(f, "could not parse a number from {:?}", s),
+            ParseLengthError::UnitMismatch { expected, found } =>
+                write!(f, "expected unit {:?} but found {:?}", expected, found),
+        }
+    }
+}
+
+// inverse of Display: parses strings like "5.05 meters" back into a Length<T>
+impl<T> FromStr for Length<T> where T: LengthUnit {
+    type Err = ParseLengthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split_at = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+            .ok_or_else(|| ParseLengthError::MalformedNumber(s.to_string()))?;
+        let (num_part, unit_part) = s.split_at(split_at);
+
+        let value: f64 = num_part
+            .parse()
+            .map_err(|_| ParseLengthError::MalformedNumber(s.to_string()))?;
+
+        let unit_part = unit_part.trim();
+        let singular = T::singular_name();
+        let plural = format!("{}s", singular);
+        if unit_part != singular && unit_part != plural {
+            return Err(ParseLengthError::UnitMismatch {
+                expected: singular,
+                found: unit_part.to_string(),
+            });
+        }
+
+        Ok(Length::saturating_from_unit_value(value))
+    }
+}
+
+
+// serde support, behind the `serde` feature: Length is serialized as
+// `{ "value": <number in T's unit>, "unit": "<T::singular_name()>" }`
+// rather than the raw nm field, so the unit is legible (and checkable) in
+// the serialized form. PhantomData<T> means we can't just #[derive] this,
+// since T itself has no reason to implement Serialize/Deserialize.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Length<T> where T: LengthUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Length", 2)?;
+        state.serialize_field("value", &f64::from(*self))?;
+        state.serialize_field("unit", &T::singular_name())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Length<T> where T: LengthUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field { Value, Unit }
+
+        fn build<T, E>(value: f64, unit: String) -> Result<Length<T>, E>
+            where T: LengthUnit,
+                  E: serde::de::Error
+        {
+            let expected = T::singular_name();
+            if unit != expected {
+                return Err(serde::de::Error::custom(format!(
+                    "unit mismatch: expected `{}` but found `{}`",
+                    expected, unit
+                )));
+            }
+            Length::try_from_unit_value(value).map_err(serde::de::Error::custom)
+        }
+
+        struct LengthVisitor<T: LengthUnit>(PhantomData<T>);
+
+        impl<'de, T: LengthUnit> serde::de::Visitor<'de> for LengthVisitor<T> {
+            type Value = Length<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a struct with a numeric `value` field and a matching `unit` field")
+            }
+
+            // non-self-describing formats (e.g. bincode) encode struct
+            // fields positionally and drive the visitor through here
+            fn visit_seq<A>(self, mut seq: A) -> Result<Length<T>, A::Error>
+                where A: serde::de::SeqAccess<'de>
+            {
+                let value = seq.next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let unit = seq.next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                build(value, unit)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Length<T>, A::Error>
+                where A: serde::de::MapAccess<'de>
+            {
+                let mut value: Option<f64> = None;
+                let mut unit: Option<String> = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Value => value = Some(map.next_value()?),
+                        Field::Unit => unit = Some(map.next_value()?),
+                    }
+                }
+                let value = value.ok_or_else(|| serde::de::Error::missing_field("value"))?;
+                let unit = unit.ok_or_else(|| serde::de::Error::missing_field("unit"))?;
+                build(value, unit)
+            }
+        }
+
+        deserializer.deserialize_struct("Length", &["value", "unit"], LengthVisitor(PhantomData))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_json_with_its_own_unit() {
+        let l = Length::<Meters>::from(5.05);
+        let json = serde_json::to_string(&l).unwrap();
+        assert_eq!(json, r#"{"value":5.05,"unit":"meter"}"#);
+        let back: Length<Meters> = serde_json::from_str(&json).unwrap();
+        assert_eq!(l, back);
+    }
+
+    #[test]
+    fn rejects_a_mismatched_unit_tag() {
+        let err = serde_json::from_str::<Length<Meters>>(r#"{"value":5.05,"unit":"kilometer"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("unit mismatch"));
+    }
+}
+
+
 macro_rules! ImplFromLengthUnit {
     ($N:ty) => {
         // from number $N to Length<T>
@@ -86,9 +252,33 @@ macro_rules! ImplFromLengthUnit {
     };
 }
 
-// Implement conversions for i64 and f64
+// Implement conversions for i64
 ImplFromLengthUnit!(i64);
-ImplFromLengthUnit!(f64);
+
+// f64 conversions go through the validating/saturating constructor below
+// instead of the macro, since a float can be NaN, infinite, or big enough
+// to overflow the i64 nm field.
+
+// from number f64 to Length<T>
+impl<T> From<f64> for Length<T> where T: LengthUnit {
+    fn from(n: f64) -> Self {
+        Length::saturating_from_unit_value(n)
+    }
+}
+
+// from number &'a f64 to Length<T>, needed for conversion macros
+impl<'a, T> From<&'a f64> for Length<T> where T: LengthUnit {
+    fn from(n: &'a f64) -> Self {
+        Length::saturating_from_unit_value(*n)
+    }
+}
+
+// from Length<T> to number f64
+impl<T> From<Length<T>> for f64 where T: LengthUnit {
+    fn from(l: Length<T>) -> f64 {
+        (l.nm as f64) / (T::num_nm_in_unit() as f64)
+    }
+}
 
 
 // transformation operation from one length type to another
@@ -150,6 +340,96 @@ impl<T1, T2> Div<Length<T2>> for Length<T1>
     }
 }
 
+// Allow a length to be negated
+impl<T> Neg for Length<T> where T: LengthUnit {
+    type Output = Length<T>;
+
+    fn neg(self) -> Length<T> {
+        Length {
+            nm: -self.nm,
+            unit: PhantomData,
+        }
+    }
+}
+
+// Allow the remainder of a length divided by a length
+impl<T1, T2> Rem<Length<T2>> for Length<T1>
+    where T1: LengthUnit,
+          T2: LengthUnit
+{
+    type Output = Length<T1>;
+
+    fn rem(self, other: Length<T2>) -> Length<T1> {
+        // cast to f64 first, like the sibling Div<Length<T2>> impl, so a
+        // zero-length divisor yields NaN instead of panicking
+        Length {
+            nm: ((self.nm as f64) % (other.nm as f64)) as i64,
+            unit: PhantomData,
+        }
+    }
+}
+
+// Allow a length to be added to in place
+impl<T1, T2> AddAssign<Length<T2>> for Length<T1>
+    where T1: LengthUnit,
+          T2: LengthUnit
+{
+    fn add_assign(&mut self, other: Length<T2>) {
+        self.nm += other.nm;
+    }
+}
+
+// Allow a length to be subtracted from in place
+impl<T1, T2> SubAssign<Length<T2>> for Length<T1>
+    where T1: LengthUnit,
+          T2: LengthUnit
+{
+    fn sub_assign(&mut self, other: Length<T2>) {
+        self.nm -= other.nm;
+    }
+}
+
+// Macro to implement the *Assign traits both ways
+// for $num_type and Length
+macro_rules! ImplMulandDivAssignLengthAndNum {
+    ($num_type:ty) => {
+        impl<T> MulAssign<$num_type> for Length<T> where T: LengthUnit {
+            fn mul_assign(&mut self, other: $num_type) {
+                self.nm = ((self.nm as $num_type) * other) as i64;
+            }
+        }
+        impl<T> DivAssign<$num_type> for Length<T> where T: LengthUnit {
+            fn div_assign(&mut self, other: $num_type) {
+                self.nm = ((self.nm as $num_type) / other) as i64;
+            }
+        }
+    };
+}
+
+ImplMulandDivAssignLengthAndNum!(i64);
+
+// MulAssign<f64>/DivAssign<f64> go through the same saturating_from_nm_f64
+// helper as the non-assign Mul<f64>/Div<f64> impls, so a NaN/infinite/
+// overflowing result is clamped rather than relying on the incidental
+// behavior of a raw `as i64` cast.
+impl<T> MulAssign<f64> for Length<T> where T: LengthUnit {
+    fn mul_assign(&mut self, other: f64) {
+        *self = Length::saturating_from_nm_f64((self.nm as f64) * other);
+    }
+}
+impl<T> DivAssign<f64> for Length<T> where T: LengthUnit {
+    fn div_assign(&mut self, other: f64) {
+        *self = Length::saturating_from_nm_f64((self.nm as f64) / other);
+    }
+}
+
+// Allow a Vec<Length<T>> (or any iterator of lengths) to be .sum()ed
+impl<T> Sum<Length<T>> for Length<T> where T: LengthUnit {
+    fn sum<I: Iterator<Item = Length<T>>>(iter: I) -> Length<T> {
+        iter.fold(Length { nm: 0, unit: PhantomData }, |acc, l| acc + l)
+    }
+}
+
 // Macro to implement multiplication and division both ways
 // for $num_type and Length
 macro_rules! ImplMulandDivLengthAndNum {
@@ -197,9 +477,40 @@ macro_rules! ImplMulandDivLengthAndNum {
     };
 }
 
-// implement multiplication and division of Lengths for i64 and u64
+// implement multiplication and division of Lengths for i64
 ImplMulandDivLengthAndNum!(i64);
-ImplMulandDivLengthAndNum!(f64);
+
+// Multiplication and division by f64 go through `saturating_from_nm_f64`
+// instead of a raw `as i64` cast, so a NaN/infinite/overflowing result is
+// clamped rather than turned into garbage.
+impl<T> Mul<f64> for Length<T> where T: LengthUnit {
+    type Output = Length<T>;
+
+    fn mul(self, other: f64) -> Length<T> {
+        Length::saturating_from_nm_f64((self.nm as f64) * other)
+    }
+}
+impl<T> Mul<Length<T>> for f64 where T: LengthUnit {
+    type Output = Length<T>;
+
+    fn mul(self, other: Length<T>) -> Length<T> {
+        other * self
+    }
+}
+impl<T> Div<f64> for Length<T> where T: LengthUnit {
+    type Output = Length<T>;
+
+    fn div(self, other: f64) -> Length<T> {
+        Length::saturating_from_nm_f64((self.nm as f64) / other)
+    }
+}
+impl<T> Div<Length<T>> for f64 where T: LengthUnit {
+    type Output = Length<T>;
+
+    fn div(self, other: Length<T>) -> Length<T> {
+        Length::saturating_from_nm_f64((other.nm as f64) / self)
+    }
+}
 
 
 // implement PartialEq for comparing Lengths with different units
@@ -217,6 +528,387 @@ impl<T1,T2> PartialOrd<Length<T2>> for Length<T1> where T1: LengthUnit, T2: Leng
 }
 
 
+// error returned by Length's validating constructor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LengthError {
+    NonFinite,
+    Overflow,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LengthError::NonFinite => write!(f, "length value is NaN or infinite"),
+            LengthError::Overflow => write!(f, "length value overflows i64 nanometers"),
+        }
+    }
+}
+
+impl<T> Length<T> where T: LengthUnit {
+
+    // Checked arithmetic: None on i64 overflow instead of wrapping.
+    fn checked_add<T2: LengthUnit>(self, other: Length<T2>) -> Option<Length<T>> {
+        self.nm.checked_add(other.nm).map(|nm| Length { nm, unit: PhantomData })
+    }
+
+    fn checked_sub<T2: LengthUnit>(self, other: Length<T2>) -> Option<Length<T>> {
+        self.nm.checked_sub(other.nm).map(|nm| Length { nm, unit: PhantomData })
+    }
+
+    fn checked_mul(self, other: i64) -> Option<Length<T>> {
+        self.nm.checked_mul(other).map(|nm| Length { nm, unit: PhantomData })
+    }
+
+    // Saturating arithmetic: clamp to i64::MIN/MAX instead of wrapping.
+    fn saturating_add<T2: LengthUnit>(self, other: Length<T2>) -> Length<T> {
+        Length { nm: self.nm.saturating_add(other.nm), unit: PhantomData }
+    }
+
+    fn saturating_sub<T2: LengthUnit>(self, other: Length<T2>) -> Length<T> {
+        Length { nm: self.nm.saturating_sub(other.nm), unit: PhantomData }
+    }
+
+    // Validating constructor from a value expressed in T's own unit (e.g.
+    // 5.05 for Length::<Meters>). Rejects NaN/infinite input and rejects
+    // overflow of the i64 nm field instead of silently producing garbage.
+    fn try_from_unit_value(value: f64) -> Result<Length<T>, LengthError> {
+        if !value.is_finite() {
+            return Err(LengthError::NonFinite);
+        }
+        Length::try_from_nm_f64(value * (T::num_nm_in_unit() as f64))
+    }
+
+    fn try_from_nm_f64(nm: f64) -> Result<Length<T>, LengthError> {
+        if !nm.is_finite() {
+            return Err(LengthError::NonFinite);
+        }
+        if nm > (i64::MAX as f64) || nm < (i64::MIN as f64) {
+            return Err(LengthError::Overflow);
+        }
+        Ok(Length { nm: nm as i64, unit: PhantomData })
+    }
+
+    // Infallible counterparts used by `From<f64>` and the `Mul<f64>`/`Div<f64>`
+    // impls: NaN becomes a zero length and overflow saturates to i64::MIN/MAX
+    // rather than wrapping or casting NaN/inf straight into `i64`.
+    fn saturating_from_unit_value(value: f64) -> Length<T> {
+        Length::saturating_from_nm_f64(value * (T::num_nm_in_unit() as f64))
+    }
+
+    fn saturating_from_nm_f64(nm: f64) -> Length<T> {
+        let nm = if nm.is_nan() { 0.0 } else { nm };
+        Length { nm: nm.max(i64::MIN as f64).min(i64::MAX as f64) as i64, unit: PhantomData }
+    }
+}
+
+
+// ----------------------------------------------------------------------
+// Duration: a second base quantity, stored in integer nanoseconds, so
+// that dividing a Length by a Duration can yield a typed Speed instead
+// of collapsing to a bare f64.
+// ----------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Eq, Ord)]
+struct Duration<T: DurationUnit> {
+    ns: i64,
+    unit: PhantomData<T>,
+}
+
+trait DurationUnit: Copy + Eq {
+    fn singular_name() -> String;
+    fn num_ns_in_unit() -> i64;
+}
+
+macro_rules! NewDuration {
+    ($struct_name:ident, $string_name:expr , $ns_conv:expr) => {
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        struct $struct_name; // unit-like struct
+
+        impl DurationUnit for $struct_name {
+            #[inline(always)]
+            fn singular_name() -> String { $string_name.to_string() }
+            #[inline(always)]
+            fn num_ns_in_unit() -> i64 { $ns_conv }
+        }
+
+    };
+}
+
+NewDuration!(Seconds, "second", 1_000_000_000);
+NewDuration!(Milliseconds, "millisecond", 1_000_000);
+NewDuration!(Minutes, "minute", 60_000_000_000);
+
+
+impl<T> fmt::Display for Duration<T> where T: DurationUnit {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num_val = (self.ns as f64) / (T::num_ns_in_unit() as f64);
+        let name_plural_s = match num_val {
+            1_f64 => "",
+            _ => "s"
+        };
+        write!(f,
+               "{} {}{}",
+               (self.ns as f64) / (T::num_ns_in_unit() as f64),
+               T::singular_name(),
+               name_plural_s)
+    }
+}
+
+
+macro_rules! ImplFromDurationUnit {
+    ($N:ty) => {
+        // from number $N to Duration<T>
+        impl<T> From<$N> for Duration<T> where T: DurationUnit {
+            fn from(n: $N) -> Self {
+                Duration {
+                    ns: (n as i64) * T::num_ns_in_unit(),
+                    unit: PhantomData
+                }
+            }
+        }
+
+        // from number &'a $N to Duration<T>, needed for conversion macros
+        impl<'a, T> From<&'a $N> for Duration<T> where T: DurationUnit {
+            fn from(n: &'a $N) -> Self {
+                Duration {
+                    ns: (*n as i64) * T::num_ns_in_unit(),
+                    unit: PhantomData
+                }
+            }
+        }
+
+        // from Duration<T> to number $N
+        impl<T> From<Duration<T>> for $N where T: DurationUnit {
+            fn from(d: Duration<T>) -> $N {
+                ((d.ns as f64) / (T::num_ns_in_unit() as f64)) as $N
+            }
+        }
+    };
+}
+
+ImplFromDurationUnit!(i64);
+ImplFromDurationUnit!(f64);
+
+
+// transformation operation from one duration type to another
+impl<'a, T1, T2> From<&'a Duration<T1>> for Duration<T2>
+    where T1: DurationUnit,
+          T2: DurationUnit
+{
+    fn from(d: &'a Duration<T1>) -> Self {
+        Duration {
+            ns: d.ns,
+            unit: PhantomData,
+        }
+    }
+}
+
+
+// Allow durations to be added
+impl<T1, T2> Add<Duration<T2>> for Duration<T1>
+    where T1: DurationUnit,
+          T2: DurationUnit
+{
+    type Output = Duration<T1>;
+
+    fn add(self, other: Duration<T2>) -> Duration<T1> {
+        Duration {
+            ns: self.ns + other.ns,
+            unit: PhantomData,
+        }
+    }
+}
+
+// Allow durations to be subtracted
+impl<T1, T2> Sub<Duration<T2>> for Duration<T1>
+    where T1: DurationUnit,
+          T2: DurationUnit
+{
+    type Output = Duration<T1>;
+
+    fn sub(self, other: Duration<T2>) -> Duration<T1> {
+        Duration {
+            ns: self.ns - other.ns,
+            unit: PhantomData,
+        }
+    }
+}
+
+// implement PartialEq for comparing Durations with different units
+impl<T1, T2> PartialEq<Duration<T2>> for Duration<T1> where T1: DurationUnit, T2: DurationUnit {
+    fn eq(&self, other: &Duration<T2>) -> bool {
+        self.ns == other.ns
+    }
+}
+
+// implement PartialOrd for ordering Durations with different units
+impl<T1, T2> PartialOrd<Duration<T2>> for Duration<T1> where T1: DurationUnit, T2: DurationUnit {
+    fn partial_cmp(&self, other: &Duration<T2>) -> Option<Ordering> {
+        Some(self.ns.cmp(&other.ns))
+    }
+}
+
+
+// ----------------------------------------------------------------------
+// Speed: the quotient of a Length and a Duration, stored in integer
+// nanometers-per-second so it stays exact in the same base-unit style
+// as Length and Duration.
+// ----------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Eq, Ord)]
+struct Speed<T: SpeedUnit> {
+    nm_per_s: i64,
+    unit: PhantomData<T>,
+}
+
+trait SpeedUnit: Copy + Eq {
+    fn singular_name() -> String;
+    fn num_nm_per_s_in_unit() -> i64;
+}
+
+macro_rules! NewSpeed {
+    ($struct_name:ident, $string_name:expr , $nm_per_s_conv:expr) => {
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        struct $struct_name; // unit-like struct
+
+        impl SpeedUnit for $struct_name {
+            #[inline(always)]
+            fn singular_name() -> String { $string_name.to_string() }
+            #[inline(always)]
+            fn num_nm_per_s_in_unit() -> i64 { $nm_per_s_conv }
+        }
+
+    };
+}
+
+NewSpeed!(MetersPerSecond, "meter per second", 1_000_000_000);
+NewSpeed!(MillimetersPerSecond, "millimeter per second", 1_000_000);
+NewSpeed!(KilometersPerSecond, "kilometer per second", 1_000_000_000_000);
+
+
+impl<T> fmt::Display for Speed<T> where T: SpeedUnit {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num_val = (self.nm_per_s as f64) / (T::num_nm_per_s_in_unit() as f64);
+        let name = T::singular_name();
+        // Speed's unit names are compound ("meter per second"), so unlike
+        // Length/Duration the plural "s" has to go on the first word, not
+        // the end of the whole string.
+        let display_name = if num_val == 1_f64 {
+            name
+        } else {
+            let mut words = name.splitn(2, ' ');
+            let first = words.next().unwrap_or("");
+            match words.next() {
+                Some(rest) => format!("{}s {}", first, rest),
+                None => format!("{}s", first),
+            }
+        };
+        write!(f, "{} {}", num_val, display_name)
+    }
+}
+
+
+// transformation operation from one speed type to another
+impl<'a, T1, T2> From<&'a Speed<T1>> for Speed<T2>
+    where T1: SpeedUnit,
+          T2: SpeedUnit
+{
+    fn from(s: &'a Speed<T1>) -> Self {
+        Speed {
+            nm_per_s: s.nm_per_s,
+            unit: PhantomData,
+        }
+    }
+}
+
+
+// Allow speeds to be added
+impl<T1, T2> Add<Speed<T2>> for Speed<T1>
+    where T1: SpeedUnit,
+          T2: SpeedUnit
+{
+    type Output = Speed<T1>;
+
+    fn add(self, other: Speed<T2>) -> Speed<T1> {
+        Speed {
+            nm_per_s: self.nm_per_s + other.nm_per_s,
+            unit: PhantomData,
+        }
+    }
+}
+
+// implement PartialEq for comparing Speeds with different units
+impl<T1, T2> PartialEq<Speed<T2>> for Speed<T1> where T1: SpeedUnit, T2: SpeedUnit {
+    fn eq(&self, other: &Speed<T2>) -> bool {
+        self.nm_per_s == other.nm_per_s
+    }
+}
+
+// implement PartialOrd for ordering Speeds with different units
+impl<T1, T2> PartialOrd<Speed<T2>> for Speed<T1> where T1: SpeedUnit, T2: SpeedUnit {
+    fn partial_cmp(&self, other: &Speed<T2>) -> Option<Ordering> {
+        Some(self.nm_per_s.cmp(&other.nm_per_s))
+    }
+}
+
+
+// ----------------------------------------------------------------------
+// Cross-type ops tying Length, Duration and Speed together: dividing a
+// Length by a Duration yields a Speed, and multiplying a Speed by a
+// Duration (either order) yields a Length back.
+// ----------------------------------------------------------------------
+
+// Allow a length to be divided by a duration, yielding a speed
+impl<T1, T2> Div<Duration<T2>> for Length<T1>
+    where T1: LengthUnit,
+          T2: DurationUnit
+{
+    type Output = Speed<MetersPerSecond>;
+
+    fn div(self, other: Duration<T2>) -> Speed<MetersPerSecond> {
+        // cast to f64 first, like the existing Length / Length Div impl,
+        // so dividing by a zero Duration yields an infinite speed instead
+        // of panicking
+        Speed {
+            nm_per_s: ((self.nm as f64) / (other.ns as f64) * 1_000_000_000.0) as i64,
+            unit: PhantomData,
+        }
+    }
+}
+
+// Allow a speed to be multiplied by a duration, yielding a length back
+impl<T1, T2> Mul<Duration<T2>> for Speed<T1>
+    where T1: SpeedUnit,
+          T2: DurationUnit
+{
+    type Output = Length<Meters>;
+
+    fn mul(self, other: Duration<T2>) -> Length<Meters> {
+        // widen to i128 so nm_per_s * ns doesn't overflow before the division
+        Length {
+            nm: ((self.nm_per_s as i128) * (other.ns as i128) / 1_000_000_000) as i64,
+            unit: PhantomData,
+        }
+    }
+}
+
+// Allow a duration to be multiplied by a speed, yielding a length (symmetric with Speed * Duration)
+impl<T1, T2> Mul<Speed<T2>> for Duration<T1>
+    where T1: DurationUnit,
+          T2: SpeedUnit
+{
+    type Output = Length<Meters>;
+
+    fn mul(self, other: Speed<T2>) -> Length<Meters> {
+        other * self
+    }
+}
+
+
 // calculate circumference of given radius
 // allows total abstraction over concept of units
 fn circumference<T>(r: Length<T>) -> Length<T> where T: LengthUnit {
@@ -238,6 +930,11 @@ macro_rules! kilometers {
     ($num:expr) => (Length::<Kilometers>::from(&$num));
 }
 
+// convert a number or duration to seconds
+macro_rules! seconds {
+    ($num:expr) => (Duration::<Seconds>::from(&$num));
+}
+
 // main function which allows easy and clean use
 // it will print the following:
 //
@@ -248,14 +945,36 @@ macro_rules! kilometers {
 // circumference(radius = 10 millimeters) = 62.831853 millimeters
 // l3 > l2 : true
 // l3 / l2 = 1.01
+// speed = l3 / d1 = 0.505 meters per second
+// distance = speed * d1 = 5.05 meters
+// l4 = -l1 = -10 millimeters
+// total = vec![l1, l2, l3].sum() = 10060 millimeters
+// l5 = l2.to_string().parse() = 5 meters
+// l1.checked_add(l2) = Some("5010 millimeters")
+// l1.checked_sub(l2) = Some("-4990 millimeters")
+// l1.checked_mul(3) = Some("30 millimeters")
+// near_max.checked_add(l2) = None
+// near_max.saturating_add(l2) = 9223372036.854776 meters
 // size_of(Length<Meters>) = 8 bytes
-fn main() {             
+fn main() {
 
     let l1 = millimeters!(10);
     let l2 = meters!(5);
     let l3 = (5 * l1) + l2;
     let l3_meters = f64::from(meters!(l3));
     let c1 = circumference(l1);
+    let d1 = seconds!(10);
+    let speed = l3 / d1;
+    let distance = speed * d1;
+    let l4 = -l1;
+    let total: Length<Millimeters> = vec![l1, millimeters!(l2), l3].into_iter().sum();
+    let l5: Length<Meters> = l2.to_string().parse().unwrap();
+    let sum_opt = l1.checked_add(l2);
+    let diff_opt = l1.checked_sub(l2);
+    let triple_opt = l1.checked_mul(3);
+    let near_max = Length::<Meters> { nm: i64::MAX - 1, unit: PhantomData };
+    let overflowed = near_max.checked_add(l2);
+    let saturated = near_max.saturating_add(l2);
 
     println!("l1 = {}", l1);
     println!("l2 = {}", l2);
@@ -264,6 +983,16 @@ fn main() {
     println!("circumference(radius = {}) = {}", l1, c1);
     println!("l3 > l2 : {}", l3 > l2);
     println!("l3 / l2 = {}", l3 / l2);
+    println!("speed = l3 / d1 = {}", speed);
+    println!("distance = speed * d1 = {}", distance);
+    println!("l4 = -l1 = {}", l4);
+    println!("total = vec![l1, l2, l3].sum() = {}", total);
+    println!("l5 = l2.to_string().parse() = {}", l5);
+    println!("l1.checked_add(l2) = {:?}", sum_opt.map(|l| l.to_string()));
+    println!("l1.checked_sub(l2) = {:?}", diff_opt.map(|l| l.to_string()));
+    println!("l1.checked_mul(3) = {:?}", triple_opt.map(|l| l.to_string()));
+    println!("near_max.checked_add(l2) = {:?}", overflowed.map(|l| l.to_string()));
+    println!("near_max.saturating_add(l2) = {}", saturated);
 
     println!("size_of(Length<Meters>) = {} bytes",
         mem::size_of::<Length<Meters>>());
@@ -293,7 +1022,7 @@ impl<T> Length<T> where T: LengthUnit {
         let mut result = 0;
         let mut candidate_result;
         while shift >= 0 {
-            result = result << 1;
+            result <<= 1;
             candidate_result = result + 1;
             if (candidate_result * candidate_result) <= (n >> shift) {
                 result = candidate_result;